@@ -69,6 +69,27 @@ impl Override {
     }
 }
 
+/// A `getProgramAccounts` filter. Maps onto the RPC `filters` schema via
+/// [`AccountFilter::to_json`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Match accounts whose data is exactly `n` bytes long.
+    DataSize(u64),
+    /// Match accounts whose data at `offset` equals the base58 `bytes`.
+    Memcmp { offset: usize, bytes: String },
+}
+
+impl AccountFilter {
+    pub fn to_json(&self) -> Value {
+        match self {
+            AccountFilter::DataSize(n) => serde_json::json!({ "dataSize": n }),
+            AccountFilter::Memcmp { offset, bytes } => serde_json::json!({
+                "memcmp": { "offset": offset, "bytes": bytes }
+            }),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum EditField{
     #[serde(with = "b58")]
@@ -79,6 +100,53 @@ pub enum EditField{
     Data(Value)
 }
 
+/// Decode a base64 payload, optionally running it through a zstd stream
+/// decoder, into the raw account bytes. Mirrors the `Base64Zstd` encoding the
+/// test-validator import path already accepts.
+fn decode_data_payload(encoding: &str, data: &str) -> Result<Vec<u8>> {
+    let raw = base64::decode(data)?;
+    match encoding {
+        "base64" => Ok(raw),
+        "base64+zstd" => Ok(zstd::stream::decode_all(&raw[..])?),
+        _ => Err(anyhow!("Unsupported data encoding: {}", encoding)),
+    }
+}
+
+/// Apply an `EditField::Data` JSON spec to an account's data buffer. Either
+/// replaces the whole buffer (`{ "encoding", "data" }`) or overlays one or
+/// more byte ranges in place (`{ "patches": [ { "offset", "encoding", "data" } ] }`)
+/// without resizing the buffer.
+fn apply_data_edit(data: &mut Vec<u8>, spec: &Value) -> Result<()> {
+    if let Some(patches) = spec.get("patches") {
+        let patches = patches.as_array().ok_or(anyhow!("patches must be an array"))?;
+        for patch in patches {
+            let offset = patch.get("offset")
+                .and_then(Value::as_u64)
+                .ok_or(anyhow!("patch missing offset"))? as usize;
+            let encoding = patch.get("encoding")
+                .and_then(Value::as_str)
+                .unwrap_or("base64");
+            let payload = patch.get("data")
+                .and_then(Value::as_str)
+                .ok_or(anyhow!("patch missing data"))?;
+            let bytes = decode_data_payload(encoding, payload)?;
+            let end = offset.checked_add(bytes.len())
+                .filter(|end| *end <= data.len())
+                .ok_or(anyhow!("patch at offset {} runs past data length {}", offset, data.len()))?;
+            data[offset..end].copy_from_slice(&bytes);
+        }
+    } else {
+        let encoding = spec.get("encoding")
+            .and_then(Value::as_str)
+            .ok_or(anyhow!("Data override missing encoding"))?;
+        let payload = spec.get("data")
+            .and_then(Value::as_str)
+            .ok_or(anyhow!("Data override missing data"))?;
+        *data = decode_data_payload(encoding, payload)?;
+    }
+    Ok(())
+}
+
 impl From<ConfigJson> for Valid8Context {
     fn from(value: ConfigJson) -> Self {
 
@@ -279,6 +347,38 @@ impl Valid8Context {
         self.try_save_config()
     }
 
+    pub fn add_program_accounts(&mut self, network: &Network, program_id: &Pubkey, filters: Vec<AccountFilter>) -> Result<()> {
+        // Build the getProgramAccounts request config: base64 encoding plus
+        // the caller's filters mapped onto the RPC `filters` schema.
+        let config = serde_json::json!({
+            "encoding": "base64",
+            "filters": filters.iter().map(AccountFilter::to_json).collect::<Vec<Value>>(),
+        });
+
+        // Issue a single getProgramAccounts call and convert the returned
+        // { pubkey, account } pairs into AccountSchemas.
+        let cloned = helpers::fetch_program_accounts(network, program_id, &config)?;
+
+        // Skip accounts already present, push the rest, and save the config
+        // once after the whole set is merged rather than per-account.
+        for account in cloned {
+            if self.has_account(&account.pubkey) {
+                continue;
+            }
+            self.accounts.push(account);
+        }
+
+        self.networks.insert(network.clone());
+
+        // Ensure the owning program itself is cloned so the forked accounts
+        // have something to execute against.
+        if !self.has_program(program_id) {
+            self.add_program_unchecked(network, program_id)?;
+        }
+
+        self.try_save_config()
+    }
+
     pub fn add_account(&mut self, network: &Network, pubkey: &Pubkey) -> Result<()> {
         // Check if we have the account in our accounts
         if self.has_account(&pubkey) {
@@ -360,7 +460,9 @@ impl Valid8Context {
                 account.owner = new_owner
             },
             EditField::UpgradeAuthority(_new_pubkey) => return Err(anyhow!("No upgrade authoprity on account")),
-            EditField::Data(_) => todo!(),
+            EditField::Data(ref spec) => {
+                apply_data_edit(&mut account.data, spec)?
+            },
         }
 
         helpers::save_account_to_disc(&self.project_name, &account)?;
@@ -391,8 +493,8 @@ impl Valid8Context {
                 acc.set_state(&new_statue)?;
                 program_data_account = AccountSchema::from_account(&acc, &program_data_account.pubkey, &program_data_account.network)?;
             },
-            EditField::Data(_json_value) => {
-                
+            EditField::Data(spec) => {
+                apply_data_edit(&mut program_data_account.data, spec)?
             },
 
         }
@@ -434,6 +536,209 @@ impl Valid8Context {
     //     Ok(())
     // }
 
+    pub fn decode_account(&self, pubkey: &Pubkey) -> Result<Value> {
+        let account = self.accounts.iter()
+            .find(|acc| acc.pubkey == *pubkey)
+            .ok_or(anyhow!("No account found in context; Decode"))?;
+
+        if !self.idls.iter().any(|id| id == &account.owner.to_string()) {
+            return Err(anyhow!("No IDL for owning program {}", account.owner));
+        }
+        let idl = helpers::read_idl_from_disc(&self.project_name, &account.owner)?;
+
+        let (_name, fields, _consumed) = decode_with_idl(&idl, &account.data)?;
+        Ok(fields)
+    }
+
+    pub fn edit_account_field(&mut self, pubkey: &Pubkey, field_path: &str, new_value: Value) -> Result<()> {
+        let (owner, data) = {
+            let account = self.accounts.iter()
+                .find(|acc| acc.pubkey == *pubkey)
+                .ok_or(anyhow!("No account found in context; Edit field"))?;
+            (account.owner, account.data.clone())
+        };
+
+        if !self.idls.iter().any(|id| id == &owner.to_string()) {
+            return Err(anyhow!("No IDL for owning program {}", owner));
+        }
+        let idl = helpers::read_idl_from_disc(&self.project_name, &owner)?;
+
+        // Decode, set the addressed field, then re-encode preserving the
+        // discriminator and field order.
+        let (name, mut decoded, consumed) = decode_with_idl(&idl, &data)?;
+        set_field_path(&mut decoded, field_path, new_value)?;
+
+        let fields = idl_account_fields(&idl, &name)?;
+        let mut out = account_discriminator(&name).to_vec();
+        for field in &fields {
+            let fname = field.get("name").and_then(Value::as_str).ok_or(anyhow!("field missing name"))?;
+            let fty = field.get("type").ok_or(anyhow!("field missing type"))?;
+            let fvalue = decoded.get(fname).ok_or(anyhow!("missing field {}", fname))?;
+            borsh_write(fty, fvalue, &mut out)?;
+        }
+
+        // The re-encoded struct replaces the original struct region; the
+        // trailing bytes after it (Anchor padding) are carried over verbatim.
+        // Editing a variable-length field (`string`/`vec`) would otherwise
+        // shift the account's total length, so reject any edit that changes the
+        // encoded struct size to keep the fixed-size allocation intact.
+        if out.len() != consumed {
+            return Err(anyhow!(
+                "edit changes encoded struct length from {} to {}; edits that resize a variable-length field are not supported",
+                consumed, out.len()
+            ));
+        }
+        out.extend_from_slice(&data[consumed..]);
+
+        // Persist and replay through the existing Data override path.
+        self.edit_account(pubkey, EditField::Data(serde_json::json!({
+            "encoding": "base64",
+            "data": base64::encode(&out),
+        })))
+    }
+
+    pub fn replace_program_binary(&mut self, program_id: &Pubkey, so_path: &Path) -> Result<()> {
+        let elf = std::fs::read(so_path)?;
+
+        // Resolve the program's ProgramData account.
+        let program = self.programs.iter()
+            .find(|prog| prog.pubkey == *program_id)
+            .ok_or(anyhow!("Program {} not cloned", program_id))?;
+        let programdata_address = match program.to_account()?.state()? {
+            UpgradeableLoaderState::Program { programdata_address } => programdata_address,
+            _ => return Err(anyhow!("{} is not an upgradeable program", program_id)),
+        };
+
+        let program_data = self.accounts.iter()
+            .find(|acc| acc.pubkey == programdata_address)
+            .ok_or(anyhow!("No ProgramData account for {}", program_id))?;
+        let current_lamports = program_data.lamports;
+
+        // Preserve the existing upgrade authority unless overridden.
+        let upgrade_authority_address = match program_data.to_account()?.state()? {
+            UpgradeableLoaderState::ProgramData { upgrade_authority_address, .. } => upgrade_authority_address,
+            _ => None,
+        };
+
+        // Rebuild the buffer as a ProgramData header followed by the new ELF.
+        let offset = UpgradeableLoaderState::size_of_programdata_metadata();
+        let len = offset + elf.len();
+        let mut data = vec![0u8; len];
+        let state = UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address };
+        bincode::serialize_into(&mut data[..offset], &state)?;
+        data[offset..].copy_from_slice(&elf);
+
+        // Grow lamports to the rent-exempt minimum for the new size, then
+        // overwrite the data. Both edits are recorded as overrides so
+        // create_ledger / export reproduce the swapped binary.
+        self.edit_account(&programdata_address, EditField::Lamports(current_lamports.max(Rent::default().minimum_balance(len))))?;
+        self.edit_account(&programdata_address, EditField::Data(serde_json::json!({
+            "encoding": "base64",
+            "data": base64::encode(&data),
+        })))?;
+
+        Ok(())
+    }
+
+    pub fn export_test_validator(&self) -> Result<String> {
+        // Write one CliAccount JSON file per account and gather `--account`
+        // arguments for each. This is the shape test-validator's
+        // `--account <pubkey> <file>` flag reads.
+        let resources = self.project_name.to_resources();
+
+        // A program's ProgramData account is loaded via `--upgradeable-program`
+        // below; emitting it a second time as `--account` makes stock
+        // test-validator reject the duplicate, so collect those addresses first
+        // and skip them from the `--account` list.
+        let programdata: HashSet<Pubkey> = self.programs.iter()
+            .filter_map(|program| program.to_account().ok())
+            .filter_map(|acc| match acc.state() {
+                Ok(UpgradeableLoaderState::Program { programdata_address }) => Some(programdata_address),
+                _ => None,
+            })
+            .collect();
+
+        let mut account_args: Vec<String> = vec![];
+        for account in &self.accounts {
+            if programdata.contains(&account.pubkey) {
+                continue;
+            }
+            let path = self.write_cli_account(&resources, account)?;
+            account_args.push(format!("--account {} {}", account.pubkey, path.display()));
+        }
+
+        // For each cloned program, dump the ELF and wire up the matching
+        // loader argument. Upgradeable programs additionally carry a
+        // ProgramData account (skipped from the `--account` list above).
+        let mut program_args: Vec<String> = vec![];
+        for program in &self.programs {
+            let program_account = program.to_account()?;
+            match program_account.state() {
+                Ok(UpgradeableLoaderState::Program { programdata_address }) => {
+                    let program_data = self.accounts.iter()
+                        .find(|acc| acc.pubkey == programdata_address)
+                        .ok_or(anyhow!("No ProgramData account for program {}", program.pubkey))?;
+                    let program_data_account = program_data.to_account()?;
+                    let upgrade_authority = match program_data_account.state()? {
+                        UpgradeableLoaderState::ProgramData { upgrade_authority_address, .. } => upgrade_authority_address,
+                        _ => None,
+                    };
+                    let offset = UpgradeableLoaderState::size_of_programdata_metadata();
+                    let so_path = Path::new(&resources).join(format!("{}.so", program.pubkey));
+                    File::create(&so_path)
+                        .and_then(|mut file| file.write_all(&program_data_account.data[offset..]))?;
+                    // Stock test-validator takes three positionals in the
+                    // order <ADDRESS> <SO_PATH> <UPGRADE_AUTHORITY>; there is no
+                    // loader argument. An immutable program has no authority, so
+                    // emit the literal `none` keyword (which the UPGRADE_AUTHORITY
+                    // field accepts) rather than a real pubkey.
+                    program_args.push(format!(
+                        "--upgradeable-program {} {} {}",
+                        program.pubkey,
+                        so_path.display(),
+                        upgrade_authority.map(|a| a.to_string()).unwrap_or_else(|| "none".to_string()),
+                    ));
+                }
+                _ => {
+                    // Non-upgradeable program: emit the bytecode directly.
+                    let so_path = Path::new(&resources).join(format!("{}.so", program.pubkey));
+                    File::create(&so_path)
+                        .and_then(|mut file| file.write_all(&program_account.data))?;
+                    program_args.push(format!("--bpf-program {} {}", program.pubkey, so_path.display()));
+                }
+            }
+        }
+
+        let command = format!(
+            "solana-test-validator --reset {} {}",
+            account_args.join(" "),
+            program_args.join(" "),
+        );
+        println!("{}", &command);
+        Ok(command)
+    }
+
+    /// Write a single account to disc in the CliAccount JSON shape that
+    /// test-validator's `--account` flag reads, returning the file path.
+    fn write_cli_account(&self, resources: &str, account: &AccountSchema) -> Result<PathBuf> {
+        let acc = account.to_account()?;
+        let json = serde_json::json!({
+            "pubkey": account.pubkey.to_string(),
+            "account": {
+                "lamports": acc.lamports,
+                "data": [base64::encode(&acc.data), "base64"],
+                "owner": acc.owner.to_string(),
+                "executable": acc.executable,
+                "rentEpoch": acc.rent_epoch,
+            }
+        });
+        let path = Path::new(resources).join(format!("{}.json", account.pubkey));
+        let pretty = serde_json::to_string_pretty(&json)?;
+        File::create(&path)
+            .and_then(|mut file| file.write_all(pretty.as_bytes()))?;
+        Ok(path)
+    }
+
     pub fn create_ledger(&self) -> Result<()> {
 
         // // for start, mimic the testvalidator genesis config and ledger with the necessary keys
@@ -533,4 +838,213 @@ impl Valid8Context {
 
         Ok(())
     }
+}
+
+/// The 8-byte Anchor account discriminator: `sha256("account:<Name>")[..8]`.
+fn account_discriminator(name: &str) -> [u8; 8] {
+    let preimage = format!("account:{}", name);
+    let hash = solana_sdk::hash::hash(preimage.as_bytes());
+    let mut disc = [0u8; 8];
+    disc.copy_from_slice(&hash.to_bytes()[..8]);
+    disc
+}
+
+/// Locate the IDL account type whose discriminator matches the data prefix and
+/// Borsh-deserialize its fields into a JSON object, in declaration order.
+///
+/// The returned `usize` is the number of bytes consumed (discriminator
+/// included) so callers can preserve any trailing padding when re-encoding.
+fn decode_with_idl(idl: &Value, data: &[u8]) -> Result<(String, Value, usize)> {
+    let accounts = idl.get("accounts").and_then(Value::as_array).ok_or(anyhow!("IDL has no accounts"))?;
+    if data.len() < 8 {
+        return Err(anyhow!("Account data too short for discriminator"));
+    }
+    for account in accounts {
+        let name = account.get("name").and_then(Value::as_str).ok_or(anyhow!("IDL account missing name"))?;
+        if account_discriminator(name) == data[..8] {
+            let fields = idl_account_fields(idl, name)?;
+            let mut reader = BorshReader { data: &data[8..], pos: 0 };
+            let mut obj = serde_json::Map::new();
+            for field in &fields {
+                let fname = field.get("name").and_then(Value::as_str).ok_or(anyhow!("field missing name"))?;
+                let fty = field.get("type").ok_or(anyhow!("field missing type"))?;
+                obj.insert(fname.to_string(), borsh_read(fty, &mut reader)?);
+            }
+            return Ok((name.to_string(), Value::Object(obj), 8 + reader.pos));
+        }
+    }
+    Err(anyhow!("No matching account discriminator in IDL"))
+}
+
+/// The `type.fields` array for the named IDL account type.
+fn idl_account_fields(idl: &Value, name: &str) -> Result<Vec<Value>> {
+    idl.get("accounts").and_then(Value::as_array)
+        .ok_or(anyhow!("IDL has no accounts"))?
+        .iter()
+        .find(|acc| acc.get("name").and_then(Value::as_str) == Some(name))
+        .and_then(|acc| acc.get("type"))
+        .and_then(|ty| ty.get("fields"))
+        .and_then(Value::as_array)
+        .cloned()
+        .ok_or(anyhow!("IDL account {} has no fields", name))
+}
+
+/// Set the dotted `field_path` inside a decoded struct to `new_value`.
+fn set_field_path(value: &mut Value, field_path: &str, new_value: Value) -> Result<()> {
+    let parts: Vec<&str> = field_path.split('.').collect();
+    let (last, parents) = parts.split_last().ok_or(anyhow!("empty field path"))?;
+    let mut cursor = value;
+    for part in parents {
+        cursor = cursor.as_object_mut()
+            .ok_or(anyhow!("field path traverses a non-object"))?
+            .get_mut(*part)
+            .ok_or(anyhow!("no field {}", part))?;
+    }
+    cursor.as_object_mut()
+        .ok_or(anyhow!("field path traverses a non-object"))?
+        .insert(last.to_string(), new_value);
+    Ok(())
+}
+
+/// A little-endian cursor over raw Borsh-encoded account data.
+struct BorshReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BorshReader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.pos + n > self.data.len() {
+            return Err(anyhow!("unexpected end of account data"));
+        }
+        let slice = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(slice)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+}
+
+/// Deserialize a single IDL-typed Borsh value into a serde `Value`.
+fn borsh_read(ty: &Value, reader: &mut BorshReader) -> Result<Value> {
+    if let Some(prim) = ty.as_str() {
+        return match prim {
+            "u8" => Ok(Value::from(reader.take(1)?[0] as u64)),
+            "u16" => Ok(Value::from(u16::from_le_bytes(reader.take(2)?.try_into().unwrap()) as u64)),
+            "u32" => Ok(Value::from(u32::from_le_bytes(reader.take(4)?.try_into().unwrap()) as u64)),
+            "u64" => Ok(Value::from(u64::from_le_bytes(reader.take(8)?.try_into().unwrap()))),
+            "i8" => Ok(Value::from(reader.take(1)?[0] as i8 as i64)),
+            "i16" => Ok(Value::from(i16::from_le_bytes(reader.take(2)?.try_into().unwrap()) as i64)),
+            "i32" => Ok(Value::from(i32::from_le_bytes(reader.take(4)?.try_into().unwrap()) as i64)),
+            "i64" => Ok(Value::from(i64::from_le_bytes(reader.take(8)?.try_into().unwrap()))),
+            // 128-bit integers do not fit in a serde_json number, so round-trip
+            // them as decimal strings.
+            "u128" => Ok(Value::from(u128::from_le_bytes(reader.take(16)?.try_into().unwrap()).to_string())),
+            "i128" => Ok(Value::from(i128::from_le_bytes(reader.take(16)?.try_into().unwrap()).to_string())),
+            "bool" => Ok(Value::from(reader.take(1)?[0] != 0)),
+            "publicKey" | "pubkey" => {
+                let pubkey = Pubkey::try_from(reader.take(32)?).map_err(|_| anyhow!("invalid pubkey bytes"))?;
+                Ok(Value::from(pubkey.to_string()))
+            }
+            "string" => {
+                let len = reader.u32()? as usize;
+                let bytes = reader.take(len)?;
+                Ok(Value::from(String::from_utf8(bytes.to_vec())?))
+            }
+            other => Err(anyhow!("unsupported IDL type {}", other)),
+        };
+    }
+
+    let obj = ty.as_object().ok_or(anyhow!("unsupported IDL type {:?}", ty))?;
+    if let Some(inner) = obj.get("vec") {
+        let len = reader.u32()? as usize;
+        let mut items = Vec::with_capacity(len);
+        for _ in 0..len {
+            items.push(borsh_read(inner, reader)?);
+        }
+        Ok(Value::Array(items))
+    } else if let Some(array) = obj.get("array").and_then(Value::as_array) {
+        let inner = array.get(0).ok_or(anyhow!("array type missing element type"))?;
+        let len = array.get(1).and_then(Value::as_u64).ok_or(anyhow!("array type missing length"))? as usize;
+        let mut items = Vec::with_capacity(len);
+        for _ in 0..len {
+            items.push(borsh_read(inner, reader)?);
+        }
+        Ok(Value::Array(items))
+    } else if let Some(inner) = obj.get("option") {
+        match reader.take(1)?[0] {
+            0 => Ok(Value::Null),
+            _ => borsh_read(inner, reader),
+        }
+    } else {
+        Err(anyhow!("unsupported IDL type {:?}", ty))
+    }
+}
+
+/// Serialize a serde `Value` back to Borsh according to its IDL type.
+fn borsh_write(ty: &Value, value: &Value, out: &mut Vec<u8>) -> Result<()> {
+    if let Some(prim) = ty.as_str() {
+        match prim {
+            "u8" => out.push(value.as_u64().ok_or(anyhow!("expected u8"))? as u8),
+            "u16" => out.extend_from_slice(&(value.as_u64().ok_or(anyhow!("expected u16"))? as u16).to_le_bytes()),
+            "u32" => out.extend_from_slice(&(value.as_u64().ok_or(anyhow!("expected u32"))? as u32).to_le_bytes()),
+            "u64" => out.extend_from_slice(&value.as_u64().ok_or(anyhow!("expected u64"))?.to_le_bytes()),
+            "i8" => out.push(value.as_i64().ok_or(anyhow!("expected i8"))? as i8 as u8),
+            "i16" => out.extend_from_slice(&(value.as_i64().ok_or(anyhow!("expected i16"))? as i16).to_le_bytes()),
+            "i32" => out.extend_from_slice(&(value.as_i64().ok_or(anyhow!("expected i32"))? as i32).to_le_bytes()),
+            "i64" => out.extend_from_slice(&value.as_i64().ok_or(anyhow!("expected i64"))?.to_le_bytes()),
+            // 128-bit integers are carried as decimal strings (see borsh_read).
+            "u128" => {
+                let n: u128 = value.as_str().ok_or(anyhow!("expected u128 string"))?.parse()?;
+                out.extend_from_slice(&n.to_le_bytes());
+            }
+            "i128" => {
+                let n: i128 = value.as_str().ok_or(anyhow!("expected i128 string"))?.parse()?;
+                out.extend_from_slice(&n.to_le_bytes());
+            }
+            "bool" => out.push(if value.as_bool().ok_or(anyhow!("expected bool"))? { 1 } else { 0 }),
+            "publicKey" | "pubkey" => {
+                let pubkey = Pubkey::from_str(value.as_str().ok_or(anyhow!("expected pubkey string"))?)?;
+                out.extend_from_slice(pubkey.as_ref());
+            }
+            "string" => {
+                let s = value.as_str().ok_or(anyhow!("expected string"))?;
+                out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+                out.extend_from_slice(s.as_bytes());
+            }
+            other => return Err(anyhow!("unsupported IDL type {}", other)),
+        }
+        return Ok(());
+    }
+
+    let obj = ty.as_object().ok_or(anyhow!("unsupported IDL type {:?}", ty))?;
+    if let Some(inner) = obj.get("vec") {
+        let items = value.as_array().ok_or(anyhow!("expected array for vec"))?;
+        out.extend_from_slice(&(items.len() as u32).to_le_bytes());
+        for item in items {
+            borsh_write(inner, item, out)?;
+        }
+    } else if let Some(array) = obj.get("array").and_then(Value::as_array) {
+        let inner = array.get(0).ok_or(anyhow!("array type missing element type"))?;
+        let len = array.get(1).and_then(Value::as_u64).ok_or(anyhow!("array type missing length"))? as usize;
+        let items = value.as_array().ok_or(anyhow!("expected array"))?;
+        if items.len() != len {
+            return Err(anyhow!("expected {} array elements, got {}", len, items.len()));
+        }
+        for item in items {
+            borsh_write(inner, item, out)?;
+        }
+    } else if let Some(inner) = obj.get("option") {
+        if value.is_null() {
+            out.push(0);
+        } else {
+            out.push(1);
+            borsh_write(inner, value, out)?;
+        }
+    } else {
+        return Err(anyhow!("unsupported IDL type {:?}", ty));
+    }
+    Ok(())
 }
\ No newline at end of file